@@ -0,0 +1,138 @@
+use bevy_color::Color;
+use bevy_ecs::{prelude::Component, query::QueryItem};
+use bevy_math::Vec3;
+use bevy_reflect::prelude::*;
+use bevy_render::extract_component::ExtractComponent;
+
+/// Configures the "classic" computer graphics [distance fog](https://en.wikipedia.org/wiki/Fog_(rendering)) effect,
+/// in which objects appear faded out at large distances.
+///
+/// The interaction between fog and transparent objects is not currently well-defined for
+/// alpha blending, and there will be artifacts for any alpha blended objects inside of a
+/// fog volume.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct DistanceFog {
+    /// The color of the fog effect, intended for use as the primary tint for scenes.
+    ///
+    /// If you want to use functions like `Color::lcha()` to change just the
+    /// lightness of the color, consider changing `directional_light_color` as well
+    /// (or set this to a neutral color and rely on `directional_light_color` alone).
+    pub color: Color,
+
+    /// Color of directional light influence on the fog, simulating the phenomenon where
+    /// nearby fog appears to glow in the direction of a strong light in the scene.
+    ///
+    /// Set to `Color::BLACK` to disable the directional light influence on the fog.
+    pub directional_light_color: Color,
+
+    /// The exponent applied to the directional light alignment calculation. Higher values
+    /// result in a more concentrated "glow" around the direction of a directional light.
+    pub directional_light_exponent: f32,
+
+    /// Determines which falloff mode to use, and its parameters.
+    pub falloff: FogFalloff,
+}
+
+impl Default for DistanceFog {
+    fn default() -> Self {
+        DistanceFog {
+            color: Color::WHITE,
+            directional_light_color: Color::NONE,
+            directional_light_exponent: 8.0,
+            falloff: FogFalloff::Linear {
+                start: 0.0,
+                end: 100.0,
+            },
+        }
+    }
+}
+
+impl ExtractComponent for DistanceFog {
+    type QueryData = &'static DistanceFog;
+    type QueryFilter = ();
+    type Out = DistanceFog;
+
+    fn extract_component(item: QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        Some(item.clone())
+    }
+}
+
+/// Scales the effective density/extinction of a [`DistanceFog`] on the camera it's added to,
+/// without having to swap out the whole [`FogFalloff`] configuration.
+///
+/// This is useful for fading fog in and out smoothly, for example when a camera enters or
+/// leaves a cave, and is a stepping stone toward fully localized fog volumes.
+///
+/// A multiplier of `1.0` (the default) leaves the fog's configured density/extinction
+/// unchanged; `0.0` disables the visual effect of fog entirely without removing the
+/// [`DistanceFog`] component.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct FogDensityMultiplier(pub f32);
+
+impl Default for FogDensityMultiplier {
+    fn default() -> Self {
+        FogDensityMultiplier(1.0)
+    }
+}
+
+impl ExtractComponent for FogDensityMultiplier {
+    type QueryData = &'static FogDensityMultiplier;
+    type QueryFilter = ();
+    type Out = FogDensityMultiplier;
+
+    fn extract_component(item: QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        Some(*item)
+    }
+}
+
+/// Allows switching between different fog falloff modes, and configures their parameters.
+#[derive(Debug, Clone, Reflect)]
+pub enum FogFalloff {
+    /// A linear fog falloff that grows in intensity between `start` and `end` distances.
+    Linear {
+        /// Distance from the camera where fog begins to apply.
+        start: f32,
+        /// Distance from the camera where fog intensity is 100%.
+        end: f32,
+    },
+
+    /// A classic exponential fog falloff, parameterized by a `density` parameter.
+    Exponential {
+        /// Determines the rate of falloff. Higher values produce thicker, more intense fog.
+        density: f32,
+    },
+
+    /// A more realistic exponential fog falloff, parameterized by a `density` parameter,
+    /// with fog intensity increasing with the square of the distance.
+    ExponentialSquared {
+        /// Determines the rate of falloff. Higher values produce thicker, more intense fog.
+        density: f32,
+    },
+
+    /// A fog falloff based on the Koschmieder contrast reduction model, with separate
+    /// `extinction` and `inscattering` colors/coefficients for a more "atmospheric" look.
+    Atmospheric {
+        /// Extinction coefficient, for each of the r, g, b color channels.
+        extinction: Vec3,
+        /// Inscattering coefficient, for each of the r, g, b color channels.
+        inscattering: Vec3,
+    },
+
+    /// An exponential height-based fog falloff, where fog density varies with world-space
+    /// altitude, allowing fog to pool in valleys and thin out over mountaintops.
+    ///
+    /// `density` sets the intensity at `base_height`, which is then scaled by `falloff` as
+    /// the camera rises above or sinks below that height.
+    ExponentialHeight {
+        /// Determines the rate of falloff at `base_height`. Higher values produce
+        /// thicker, more intense fog.
+        density: f32,
+        /// Determines how quickly fog density decreases with altitude above `base_height`.
+        /// Higher values produce a thinner band of fog.
+        falloff: f32,
+        /// The world-space height at which `density` applies.
+        base_height: f32,
+    },
+}
@@ -11,7 +11,7 @@ use bevy_render::{
     Render, RenderApp, RenderSystems,
 };
 
-use crate::{DistanceFog, FogFalloff};
+use crate::{DistanceFog, FogDensityMultiplier, FogFalloff};
 
 /// The GPU-side representation of the fog configuration that's sent as a uniform to the shader
 #[derive(Copy, Clone, ShaderType, Default, Debug)]
@@ -30,6 +30,9 @@ pub struct GpuFog {
     bi: Vec3,
     /// Unsigned int representation of the active fog falloff mode
     mode: u32,
+    /// Scales the effective density/extinction of the fog, independent of `falloff` mode.
+    /// Lets [`FogDensityMultiplier`] fade fog in/out without swapping the whole [`DistanceFog`].
+    density_factor: f32,
 }
 
 // Important: These must be kept in sync with `mesh_view_types.wgsl`
@@ -38,6 +41,7 @@ const GPU_FOG_MODE_LINEAR: u32 = 1;
 const GPU_FOG_MODE_EXPONENTIAL: u32 = 2;
 const GPU_FOG_MODE_EXPONENTIAL_SQUARED: u32 = 3;
 const GPU_FOG_MODE_ATMOSPHERIC: u32 = 4;
+const GPU_FOG_MODE_EXPONENTIAL_HEIGHT: u32 = 5;
 
 /// Metadata for fog
 #[derive(Default, Resource)]
@@ -51,7 +55,14 @@ pub fn prepare_fog(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
     mut fog_meta: ResMut<FogMeta>,
-    views: Query<(Entity, Option<&DistanceFog>), With<ExtractedView>>,
+    views: Query<
+        (
+            Entity,
+            Option<&DistanceFog>,
+            Option<&FogDensityMultiplier>,
+        ),
+        With<ExtractedView>,
+    >,
 ) {
     let views_iter = views.iter();
     let view_count = views_iter.len();
@@ -61,8 +72,9 @@ pub fn prepare_fog(
     else {
         return;
     };
-    for (entity, fog) in views_iter {
-        let gpu_fog = if let Some(fog) = fog {
+    for (entity, fog, density_multiplier) in views_iter {
+        let density_factor = density_multiplier.map_or(1.0, |multiplier| multiplier.0);
+        let mut gpu_fog = if let Some(fog) = fog {
             match &fog.falloff {
                 FogFalloff::Linear { start, end } => GpuFog {
                     mode: GPU_FOG_MODE_LINEAR,
@@ -102,6 +114,20 @@ pub fn prepare_fog(
                     directional_light_exponent: fog.directional_light_exponent,
                     be: *extinction,
                     bi: *inscattering,
+                    ..Default::default()
+                },
+                FogFalloff::ExponentialHeight {
+                    density,
+                    falloff,
+                    base_height,
+                } => GpuFog {
+                    mode: GPU_FOG_MODE_EXPONENTIAL_HEIGHT,
+                    base_color: LinearRgba::from(fog.color).to_vec4(),
+                    directional_light_color: LinearRgba::from(fog.directional_light_color)
+                        .to_vec4(),
+                    directional_light_exponent: fog.directional_light_exponent,
+                    be: Vec3::new(*density, *falloff, *base_height),
+                    ..Default::default()
                 },
             }
         } else {
@@ -111,6 +137,7 @@ pub fn prepare_fog(
                 ..Default::default()
             }
         };
+        gpu_fog.density_factor = density_factor;
 
         // This is later read by `SetMeshViewBindGroup<I>`
         commands.entity(entity).insert(ViewFogUniformOffset {
@@ -134,7 +161,11 @@ impl Plugin for FogPlugin {
         load_shader_library!(app, "fog.wgsl");
 
         app.register_type::<DistanceFog>();
-        app.add_plugins(ExtractComponentPlugin::<DistanceFog>::default());
+        app.register_type::<FogDensityMultiplier>();
+        app.add_plugins((
+            ExtractComponentPlugin::<DistanceFog>::default(),
+            ExtractComponentPlugin::<FogDensityMultiplier>::default(),
+        ));
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
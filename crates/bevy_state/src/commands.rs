@@ -0,0 +1,17 @@
+use bevy_ecs::prelude::*;
+
+use crate::state::{FreelyMutableState, NextState};
+
+/// Extension trait for [`Commands`] adding methods for working with [`States`](crate::state::States).
+pub trait CommandsStatesExt {
+    /// Queue a transition to `state` next frame.
+    fn set_state<S: FreelyMutableState>(&mut self, state: S);
+}
+
+impl CommandsStatesExt for Commands<'_, '_> {
+    fn set_state<S: FreelyMutableState>(&mut self, state: S) {
+        self.queue(move |world: &mut World| {
+            world.resource_mut::<NextState<S>>().set(state);
+        });
+    }
+}
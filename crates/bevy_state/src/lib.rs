@@ -59,6 +59,16 @@ pub mod condition;
 /// Provides definitions for the basic traits required by the state system
 pub mod state;
 
+#[cfg(feature = "bevy_app")]
+/// Provides an opt-in push/pop history on top of a [`FreelyMutableState`](state::FreelyMutableState),
+/// for the common pause-menu/overlay pattern.
+pub mod state_stack;
+
+#[cfg(feature = "bevy_app")]
+/// Provides timer- and frame-based deferred transitions for a
+/// [`FreelyMutableState`](state::FreelyMutableState).
+pub mod state_scheduled;
+
 /// Provides tools for managing the lifetime of entities based on state transitions.
 pub mod state_scoped;
 #[cfg(feature = "bevy_app")]
@@ -70,18 +80,32 @@ pub mod state_scoped_events;
 /// Provides definitions for the basic traits required by the state system
 pub mod reflect;
 
+#[cfg(all(feature = "bevy_reflect", feature = "bevy_app"))]
+/// Provides [`StateSnapshot`](state_snapshot::StateSnapshot), a serializable capture of every
+/// active state, for save games and deterministic replay.
+pub mod state_snapshot;
+
 /// The state prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[cfg(feature = "bevy_app")]
     #[doc(hidden)]
-    pub use crate::{app::AppExtStates, state_scoped_events::StateScopedEventsAppExt};
+    pub use crate::{
+        app::AppExtStates,
+        state_scheduled::{AppExtScheduledState, CommandsScheduledStateExt},
+        state_scoped_events::StateScopedEventsAppExt,
+        state_stack::{AppExtStateStack, StateStack, StateStackCommandsExt},
+    };
 
     #[cfg(feature = "bevy_reflect")]
     #[doc(hidden)]
     pub use crate::reflect::{ReflectFreelyMutableState, ReflectState};
 
+    #[cfg(all(feature = "bevy_reflect", feature = "bevy_app"))]
+    #[doc(hidden)]
+    pub use crate::state_snapshot::{StateSnapshot, StateSnapshotAppExt};
+
     #[doc(hidden)]
     pub use crate::{
         commands::CommandsStatesExt,
@@ -0,0 +1,61 @@
+use bevy_ecs::world::World;
+use bevy_reflect::{FromReflect, FromType, PartialReflect};
+
+use crate::state::{FreelyMutableState, NextState, State, States};
+
+/// A struct used to operate on the reflected [`State`] of a given type, without knowing the
+/// concrete `S`.
+#[derive(Clone)]
+pub struct ReflectState {
+    get_state: fn(&World) -> Option<Box<dyn PartialReflect>>,
+}
+
+impl ReflectState {
+    /// Gets the value of [`State<S>`] as a reflected value, for the `S` this was constructed
+    /// from, if that state is currently initialized in the `world`.
+    pub fn reflect_state(&self, world: &World) -> Option<Box<dyn PartialReflect>> {
+        (self.get_state)(world)
+    }
+}
+
+impl<S: States + FromReflect> FromType<S> for ReflectState {
+    fn from_type() -> Self {
+        Self {
+            get_state: |world| {
+                world
+                    .get_resource::<State<S>>()
+                    .map(|state| state.get().clone().into_partial_reflect())
+            },
+        }
+    }
+}
+
+/// A struct used to operate on the reflected [`NextState<S>`] of a given type, without knowing
+/// the concrete `S`, for every `S` that implements [`FreelyMutableState`].
+#[derive(Clone)]
+pub struct ReflectFreelyMutableState {
+    set_next_state: fn(&mut World, Box<dyn PartialReflect>),
+}
+
+impl ReflectFreelyMutableState {
+    /// Queues a transition to `state` (given as a reflected value) for the `S` this was
+    /// constructed from, if that state is currently initialized in the `world`.
+    pub fn set_next_state(&self, world: &mut World, state: Box<dyn PartialReflect>) {
+        (self.set_next_state)(world, state);
+    }
+}
+
+impl<S: FreelyMutableState + FromReflect> FromType<S> for ReflectFreelyMutableState {
+    fn from_type() -> Self {
+        Self {
+            set_next_state: |world, state| {
+                let Some(state) = S::from_reflect(state.as_partial_reflect()) else {
+                    return;
+                };
+                if let Some(mut next_state) = world.get_resource_mut::<NextState<S>>() {
+                    next_state.set(state);
+                }
+            },
+        }
+    }
+}
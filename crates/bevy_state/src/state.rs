@@ -0,0 +1,112 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use bevy_ecs::{
+    event::Event,
+    resource::Resource,
+    schedule::ScheduleLabel,
+};
+
+/// Types that can define world-wide states in a finite-state machine.
+///
+/// The [`States`] trait should be derived on an enum defining the possible states a part of
+/// your application can be in. It is generally used alongside [`State<S>`] and [`NextState<S>`]
+/// to tell Bevy what the current state is, and which state to move to next.
+pub trait States: 'static + Send + Sync + Clone + PartialEq + Eq + Hash + Debug {}
+
+/// A marker trait for types implementing [`States`] that can have their value directly set
+/// through [`NextState<S>`], without it needing to be computed from other states.
+///
+/// [`State<S>`]'s whose value can be computed should instead implement `SubStates` or
+/// `ComputedStates` (which add extra restrictions to the management of the state's value).
+pub trait FreelyMutableState: States {}
+
+/// The current value of a given [`States`] type.
+///
+/// This is only inserted into the `World` if the state has been initialized, usually via
+/// `App::init_state` or `App::insert_state`.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct State<S: States>(pub(crate) S);
+
+impl<S: States> State<S> {
+    /// Creates a new state with a specific value.
+    pub fn new(state: S) -> Self {
+        Self(state)
+    }
+
+    /// Returns the current state's value.
+    pub fn get(&self) -> &S {
+        &self.0
+    }
+}
+
+/// The next state of [`State<S>`].
+///
+/// To queue a transition, set this resource to `NextState::Pending(...)`, for example by
+/// calling [`CommandsStatesExt::set_state`](crate::commands::CommandsStatesExt::set_state).
+/// The actual transition happens during the [`StateTransition`] schedule.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub enum NextState<S: FreelyMutableState> {
+    /// No transition is queued; `State<S>` stays at its current value.
+    Unchanged,
+    /// A transition to the contained value is queued.
+    Pending(S),
+}
+
+impl<S: FreelyMutableState> Default for NextState<S> {
+    fn default() -> Self {
+        Self::Unchanged
+    }
+}
+
+impl<S: FreelyMutableState> NextState<S> {
+    /// Queue a transition to `state`, overwriting any transition that was already queued.
+    pub fn set(&mut self, state: S) {
+        *self = Self::Pending(state);
+    }
+}
+
+/// An `Event` sent when any state transition of type `S` happens.
+///
+/// This event is fired after the `State<S>` resource has been updated, but before the
+/// [`OnExit`] and [`OnEnter`] schedules are run.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct StateTransitionEvent<S: States> {
+    /// The state that was active before this transition, if any.
+    pub exited: Option<S>,
+    /// The state that is active after this transition, if any.
+    pub entered: Option<S>,
+}
+
+/// A [`ScheduleLabel`] for the schedule that runs whenever [`State<S>`] changes for any `S`.
+///
+/// This schedule runs [`OnExit`], then [`OnTransition`], then [`OnEnter`], for every state
+/// type, in the order the state types were added to the `App`.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StateTransition;
+
+/// A [`ScheduleLabel`] for the schedule that runs whenever `State<S>` enters the value `S`.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnEnter<S: States>(pub S);
+
+/// A [`ScheduleLabel`] for the schedule that runs whenever `State<S>` exits the value `S`.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnExit<S: States>(pub S);
+
+/// A [`ScheduleLabel`] for the schedule that runs whenever `State<S>` exits the `exited` value
+/// and enters the `entered` value.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnTransition<S: States> {
+    /// The state that is being exited.
+    pub exited: S,
+    /// The state that is being entered.
+    pub entered: S,
+}
+
+/// Returns the [`StateTransitionEvent`] of the most recent transition for state type `S`, if
+/// one occurred during the current pass of the [`StateTransition`] schedule.
+pub fn last_transition<S: States>(
+    mut reader: bevy_ecs::event::EventReader<StateTransitionEvent<S>>,
+) -> Option<StateTransitionEvent<S>> {
+    reader.read().last().cloned()
+}
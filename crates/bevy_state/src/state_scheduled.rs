@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::Time;
+
+use crate::state::{FreelyMutableState, NextState};
+
+/// A single queued transition managed by [`ScheduledStateTransition<S>`].
+enum PendingTransition<S> {
+    /// Fires once `remaining` has elapsed.
+    AfterDuration { remaining: Duration, state: S },
+    /// Fires once `remaining_frames` more [`PreUpdate`] passes have run.
+    AfterFrames { remaining_frames: u32, state: S },
+}
+
+/// Queues transitions for `S` to apply themselves after a [`Duration`] or a number of frames
+/// have passed, instead of requiring every game to hand-roll its own timer for things like
+/// auto-advancing from a splash screen, returning to gameplay after a "you died" screen, or
+/// debouncing rapid input.
+///
+/// Transitions are queued with
+/// [`CommandsScheduledStateExt::set_state_after`]/[`CommandsScheduledStateExt::set_state_next_frame`]
+/// and are ticked down in [`PreUpdate`], ahead of the
+/// [`StateTransition`](crate::state::StateTransition) schedule that actually applies them via
+/// [`NextState<S>`].
+#[derive(Resource)]
+pub struct ScheduledStateTransition<S: FreelyMutableState> {
+    pending: Vec<PendingTransition<S>>,
+}
+
+impl<S: FreelyMutableState> Default for ScheduledStateTransition<S> {
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<S: FreelyMutableState> ScheduledStateTransition<S> {
+    /// Returns `true` if there is at least one transition still queued.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
+
+/// Extension trait for [`Commands`] adding timer- and frame-based transitions for states that
+/// opt in by initializing a [`ScheduledStateTransition<S>`] resource, e.g. via
+/// [`AppExtScheduledState::init_scheduled_state_transition`].
+pub trait CommandsScheduledStateExt {
+    /// Queues a transition to `state` to fire once `delay` has elapsed.
+    ///
+    /// If another transition for `S` is already queued, both will eventually fire in the order
+    /// their delays elapse; since [`NextState::set`] just overwrites the pending value, only
+    /// the last one to fire in a given frame actually takes effect.
+    fn set_state_after<S: FreelyMutableState>(&mut self, state: S, delay: Duration);
+
+    /// Queues a transition to `state` to fire on the next [`PreUpdate`] pass, so that it is
+    /// applied by the [`StateTransition`](crate::state::StateTransition) schedule of that frame.
+    /// Several calls within one frame all fire on that same pass, and the last one queued takes
+    /// effect.
+    fn set_state_next_frame<S: FreelyMutableState>(&mut self, state: S);
+}
+
+impl CommandsScheduledStateExt for Commands<'_, '_> {
+    fn set_state_after<S: FreelyMutableState>(&mut self, state: S, delay: Duration) {
+        self.queue(move |world: &mut World| {
+            world
+                .resource_mut::<ScheduledStateTransition<S>>()
+                .pending
+                .push(PendingTransition::AfterDuration {
+                    remaining: delay,
+                    state,
+                });
+        });
+    }
+
+    fn set_state_next_frame<S: FreelyMutableState>(&mut self, state: S) {
+        self.queue(move |world: &mut World| {
+            world
+                .resource_mut::<ScheduledStateTransition<S>>()
+                .pending
+                .push(PendingTransition::AfterFrames {
+                    remaining_frames: 1,
+                    state,
+                });
+        });
+    }
+}
+
+/// Ticks down every queued transition for `S` and applies the ones that are due via
+/// [`NextState<S>`].
+fn tick_scheduled_state_transitions<S: FreelyMutableState>(
+    time: Res<Time>,
+    mut scheduled: ResMut<ScheduledStateTransition<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    let delta = time.delta();
+    scheduled.pending.retain_mut(|pending| {
+        let due = match pending {
+            PendingTransition::AfterDuration { remaining, .. } => {
+                *remaining = remaining.saturating_sub(delta);
+                remaining.is_zero()
+            }
+            PendingTransition::AfterFrames {
+                remaining_frames, ..
+            } => {
+                *remaining_frames = remaining_frames.saturating_sub(1);
+                *remaining_frames == 0
+            }
+        };
+        if due {
+            let state = match pending {
+                PendingTransition::AfterDuration { state, .. }
+                | PendingTransition::AfterFrames { state, .. } => state.clone(),
+            };
+            next_state.set(state);
+        }
+        !due
+    });
+}
+
+/// Extension trait for [`App`] that opts a [`FreelyMutableState`] type into timer- and
+/// frame-based transitions (see [`ScheduledStateTransition<S>`] and
+/// [`CommandsScheduledStateExt`]).
+pub trait AppExtScheduledState {
+    /// Initializes an empty [`ScheduledStateTransition<S>`] for `S` and schedules the system
+    /// that ticks it down every frame.
+    fn init_scheduled_state_transition<S: FreelyMutableState>(&mut self) -> &mut Self;
+}
+
+impl AppExtScheduledState for App {
+    fn init_scheduled_state_transition<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.init_resource::<ScheduledStateTransition<S>>()
+            .add_systems(PreUpdate, tick_scheduled_state_transitions::<S>);
+        self
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use bevy_app::App;
+use bevy_ecs::{reflect::AppTypeRegistry, world::World};
+use bevy_reflect::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    PartialReflect, TypeRegistry,
+};
+use serde::{
+    de::{DeserializeSeed, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserializer, Serialize, Serializer,
+};
+
+use crate::reflect::{ReflectFreelyMutableState, ReflectState};
+
+/// A captured, serializable copy of every currently-active [`States`](crate::state::States),
+/// [`SubStates`](crate::state::SubStates) and [`ComputedStates`](crate::state::ComputedStates)
+/// value in a `World`, discovered through the type registry rather than a hand-written list.
+///
+/// Build one with [`StateSnapshotAppExt::capture_state_snapshot`] and restore it with
+/// [`StateSnapshotAppExt::apply_state_snapshot`]. This is the basis for save games and
+/// deterministic replay that need to restore the exact state-machine configuration a scene was
+/// in, without hand-writing per-state serialization for every `States` type in the game.
+#[derive(Default)]
+pub struct StateSnapshot {
+    states: Vec<Box<dyn PartialReflect>>,
+}
+
+impl StateSnapshot {
+    /// Captures the current value of every state type registered in `registry` that has
+    /// [`ReflectState`] type data and is currently initialized in `world`.
+    pub fn capture(world: &World, registry: &TypeRegistry) -> Self {
+        let states = registry
+            .iter()
+            .filter_map(|registration| registration.data::<ReflectState>())
+            .filter_map(|reflect_state| reflect_state.reflect_state(world))
+            .collect();
+        Self { states }
+    }
+
+    /// Writes every captured state back through `NextState<S>` (via [`ReflectFreelyMutableState`]),
+    /// queuing the usual [`StateTransition`](crate::state::StateTransition) machinery to run the
+    /// matching `OnEnter`/`OnExit` schedules on the next transition pass.
+    ///
+    /// States that are no longer registered, not currently initialized in `world`, or not
+    /// freely mutable (e.g. a [`ComputedStates`](crate::state::ComputedStates), whose value is
+    /// always derived rather than restored) are skipped.
+    pub fn apply(&self, world: &mut World, registry: &TypeRegistry) {
+        for state in &self.states {
+            let Some(type_id) = state.get_represented_type_info().map(|info| info.type_id())
+            else {
+                continue;
+            };
+            let Some(reflect_mutable) = registry
+                .get(type_id)
+                .and_then(|registration| registration.data::<ReflectFreelyMutableState>())
+            else {
+                continue;
+            };
+            reflect_mutable.set_next_state(world, state.clone_value());
+        }
+    }
+}
+
+/// Serializes a [`StateSnapshot`] using the app's [`TypeRegistry`], following the same
+/// registry-aware pattern as `bevy_scene::SceneSerializer`.
+pub struct StateSnapshotSerializer<'a> {
+    /// The snapshot to serialize.
+    pub snapshot: &'a StateSnapshot,
+    /// The registry used to look up each captured state's `Serialize` implementation.
+    pub registry: &'a TypeRegistry,
+}
+
+impl Serialize for StateSnapshotSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.snapshot.states.len()))?;
+        for state in &self.snapshot.states {
+            seq.serialize_element(&ReflectSerializer::new(
+                state.as_partial_reflect(),
+                self.registry,
+            ))?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a [`StateSnapshot`] using the app's [`TypeRegistry`], following the same
+/// registry-aware pattern as `bevy_scene::SceneDeserializer`.
+pub struct StateSnapshotDeserializer<'a> {
+    /// The registry used to look up each state's `Deserialize` implementation.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for StateSnapshotDeserializer<'_> {
+    type Value = StateSnapshot;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct SeqVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for SeqVisitor<'_> {
+            type Value = StateSnapshot;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a sequence of reflected state values")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut states = Vec::new();
+                while let Some(value) =
+                    seq.next_element_seed(ReflectDeserializer::new(self.registry))?
+                {
+                    states.push(value.into_partial_reflect());
+                }
+                Ok(StateSnapshot { states })
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+/// Extension trait for [`App`] that captures and restores [`StateSnapshot`]s of the app's
+/// `World` for save/load and deterministic replay.
+pub trait StateSnapshotAppExt {
+    /// Captures a [`StateSnapshot`] of every currently-active, registered state in this app.
+    fn capture_state_snapshot(&self) -> StateSnapshot;
+
+    /// Restores a previously captured [`StateSnapshot`], queuing transitions for every state it
+    /// contains.
+    fn apply_state_snapshot(&mut self, snapshot: &StateSnapshot);
+}
+
+impl StateSnapshotAppExt for App {
+    fn capture_state_snapshot(&self) -> StateSnapshot {
+        let world = self.world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        StateSnapshot::capture(world, &registry)
+    }
+
+    fn apply_state_snapshot(&mut self, snapshot: &StateSnapshot) {
+        let registry = self.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        snapshot.apply(self.world_mut(), &registry);
+    }
+}
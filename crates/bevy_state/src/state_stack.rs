@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+
+use crate::state::{FreelyMutableState, NextState};
+
+/// An ordered history of previously-active values of `S`, maintained by
+/// [`StateStackCommandsExt::push_state`] and [`StateStackCommandsExt::pop_state`].
+///
+/// This turns the common "pause menu" / "settings overlay" pattern into first-class state
+/// machinery: instead of a game manually remembering which gameplay state to return to when a
+/// menu closes, it can `push_state` the menu on top of whatever is currently active and
+/// `pop_state` to restore it.
+///
+/// This resource is only inserted by [`AppExtStateStack::enable_state_stack`], which expects `S`
+/// to already be initialized via [`AppExtStates::init_state`](crate::app::AppExtStates) (or
+/// `insert_state`); like [`State<S>`](crate::state::State) and [`NextState<S>`], it is keyed by
+/// the state type `S`.
+#[derive(Resource, Debug, Clone)]
+pub struct StateStack<S: FreelyMutableState> {
+    stack: Vec<S>,
+}
+
+impl<S: FreelyMutableState> Default for StateStack<S> {
+    fn default() -> Self {
+        Self { stack: Vec::new() }
+    }
+}
+
+impl<S: FreelyMutableState> StateStack<S> {
+    /// The states beneath the currently active one, ordered from oldest to most recent, i.e.
+    /// the value that [`pop_state`](StateStackCommandsExt::pop_state) would restore first is
+    /// the last element.
+    pub fn history(&self) -> &[S] {
+        &self.stack
+    }
+
+    /// Returns `true` if there is a state to [`pop_state`](StateStackCommandsExt::pop_state)
+    /// back to.
+    pub fn can_pop(&self) -> bool {
+        !self.stack.is_empty()
+    }
+}
+
+/// Extension trait for [`Commands`] adding stack-based transitions for states that opt in by
+/// initializing a [`StateStack<S>`] resource alongside the usual `State<S>`/`NextState<S>`.
+pub trait StateStackCommandsExt {
+    /// Keeps the currently active value of `S` on the [`StateStack<S>`] and transitions to
+    /// `state`. This runs `OnExit` for the current value (without discarding it) and `OnEnter`
+    /// for `state`, exactly like a normal transition.
+    fn push_state<S: FreelyMutableState>(&mut self, state: S);
+
+    /// Restores the most recently pushed value of `S` from the [`StateStack<S>`], running
+    /// `OnExit` for the current value and `OnEnter` for the restored one. Does nothing if the
+    /// stack is empty.
+    fn pop_state<S: FreelyMutableState>(&mut self);
+
+    /// Transitions to `state` without touching the [`StateStack<S>`]. Equivalent to
+    /// [`CommandsStatesExt::set_state`](crate::commands::CommandsStatesExt::set_state), but
+    /// named to make the distinction from `push_state`/`pop_state` explicit at call sites.
+    fn replace_state<S: FreelyMutableState>(&mut self, state: S);
+}
+
+impl StateStackCommandsExt for Commands<'_, '_> {
+    fn push_state<S: FreelyMutableState>(&mut self, state: S) {
+        self.queue(move |world: &mut World| {
+            let current = world.resource::<crate::state::State<S>>().get().clone();
+            world.resource_mut::<StateStack<S>>().stack.push(current);
+            world.resource_mut::<NextState<S>>().set(state);
+        });
+    }
+
+    fn pop_state<S: FreelyMutableState>(&mut self) {
+        self.queue(move |world: &mut World| {
+            let Some(previous) = world.resource_mut::<StateStack<S>>().stack.pop() else {
+                return;
+            };
+            world.resource_mut::<NextState<S>>().set(previous);
+        });
+    }
+
+    fn replace_state<S: FreelyMutableState>(&mut self, state: S) {
+        self.queue(move |world: &mut World| {
+            world.resource_mut::<NextState<S>>().set(state);
+        });
+    }
+}
+
+/// Extension trait for [`App`] that opts a [`FreelyMutableState`] type into stack-based
+/// transitions (see [`StateStack<S>`] and [`StateStackCommandsExt`]).
+pub trait AppExtStateStack {
+    /// Initializes an empty [`StateStack<S>`] for `S`, which must already have been initialized
+    /// with `S`'s usual `State<S>`/`NextState<S>` (e.g. via `App::init_state`).
+    ///
+    /// After calling this, [`StateStackCommandsExt::push_state`] and
+    /// [`StateStackCommandsExt::pop_state`] can be used for `S`.
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self;
+}
+
+impl AppExtStateStack for App {
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.init_resource::<StateStack<S>>();
+        self
+    }
+}